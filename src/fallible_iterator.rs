@@ -0,0 +1,355 @@
+/// An iterator whose [`next()`](FallibleIterator::next) itself may fail,
+/// modeled on the `fallible-iterator` crate design.
+///
+/// [`TryIterator`](crate::prelude::TryIterator) only layers fallible
+/// *predicates* over an ordinary [`Iterator`] whose items happen to be
+/// `Result<T, E>`; the iterator keeps producing items even after an error,
+/// which mis-counts or loops forever when driven by something like a
+/// `Lines` reader that repeats the same IO error on every call. This trait
+/// instead makes `next` itself fallible, so an error stops the iteration
+/// for good.
+///
+/// Use [`convert()`] to bridge an existing `Iterator<Item = Result<T, E>>`
+/// into this trait, and
+/// [`iterator()`](FallibleIterator::iterator) to go back.
+///
+/// Prefer importing this trait through the crate prelude:
+///
+/// ```rust,no_run
+/// use try_iterator::prelude::*;
+/// ```
+pub trait FallibleIterator {
+	/// The type of the elements being iterated over.
+	type Item;
+
+	/// The error potentially returned by iteration.
+	type Error;
+
+	/// Advances the iterator and returns the next value, or the first
+	/// error encountered. Once an error is returned, the iterator should
+	/// not be polled again.
+	fn next(&mut self) -> Result<Option<Self::Item>, Self::Error>;
+
+	/// Takes a closure and creates a fallible iterator which calls that
+	/// closure on each element.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use try_iterator::prelude::*;
+	///
+	/// let items: Vec<Result<u32, u32>> = vec![Ok(1), Ok(2), Ok(3)];
+	///
+	/// let doubled = convert(items.into_iter())
+	///     .map(|item| item * 2)
+	///     .collect::<Vec<_>>();
+	///
+	/// assert_eq!(doubled, Ok(vec![2, 4, 6]));
+	/// ```
+	fn map<B, F>(self, f: F) -> Map<Self, F>
+		where Self: Sized,
+			F: FnMut(Self::Item) -> B,
+	{
+		Map { iter: self, f }
+	}
+
+	/// Creates a fallible iterator which uses a closure to determine if an
+	/// element should be yielded.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use try_iterator::prelude::*;
+	///
+	/// let items: Vec<Result<u32, u32>> = vec![Ok(1), Ok(2), Ok(3), Ok(4)];
+	///
+	/// let evens = convert(items.into_iter())
+	///     .filter(|item| item % 2 == 0)
+	///     .collect::<Vec<_>>();
+	///
+	/// assert_eq!(evens, Ok(vec![2, 4]));
+	/// ```
+	fn filter<P>(self, predicate: P) -> Filter<Self, P>
+		where Self: Sized,
+			P: FnMut(&Self::Item) -> bool,
+	{
+		Filter { iter: self, predicate }
+	}
+
+	/// Folds every element into an accumulator by applying an operation,
+	/// stopping at the first error and returning that error.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use try_iterator::prelude::*;
+	///
+	/// let items: Vec<Result<u32, u32>> = vec![Ok(1), Ok(2), Ok(3)];
+	///
+	/// let sum = convert(items.into_iter()).fold(0, |acc, item| acc + item);
+	///
+	/// assert_eq!(sum, Ok(6));
+	/// ```
+	fn fold<B, F>(mut self, init: B, mut f: F) -> Result<B, Self::Error>
+		where Self: Sized,
+			F: FnMut(B, Self::Item) -> B,
+	{
+		let mut acc = init;
+		while let Some(item) = self.next()? {
+			acc = f(acc, item);
+		}
+		Ok(acc)
+	}
+
+	/// Counts the number of remaining elements, stopping at the first error
+	/// and returning that error.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use try_iterator::prelude::*;
+	///
+	/// let items: Vec<Result<u32, u32>> = vec![Ok(1), Ok(2), Ok(3)];
+	///
+	/// let count = convert(items.into_iter()).count();
+	///
+	/// assert_eq!(count, Ok(3));
+	/// ```
+	fn count(self) -> Result<usize, Self::Error>
+		where Self: Sized,
+	{
+		self.fold(0, |acc, _| acc + 1)
+	}
+
+	/// Collects the remaining elements into a collection, stopping at the
+	/// first error and returning that error.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use try_iterator::prelude::*;
+	///
+	/// let items: Vec<Result<u32, u32>> = vec![Ok(1), Ok(2), Ok(3)];
+	///
+	/// let collected = convert(items.into_iter()).collect::<Vec<_>>();
+	///
+	/// assert_eq!(collected, Ok(vec![1, 2, 3]));
+	/// ```
+	fn collect<B>(self) -> Result<B, Self::Error>
+		where Self: Sized,
+			B: FromIterator<Self::Item>,
+	{
+		self.iterator().collect()
+	}
+
+	/// Tests if every element of the iterator matches a predicate, stopping
+	/// at the first error and returning that error.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use try_iterator::prelude::*;
+	///
+	/// let items: Vec<Result<u32, u32>> = vec![Ok(2), Ok(4), Ok(6)];
+	///
+	/// let all_even = convert(items.into_iter()).all(|item| item % 2 == 0);
+	///
+	/// assert_eq!(all_even, Ok(true));
+	/// ```
+	fn all<F>(&mut self, mut f: F) -> Result<bool, Self::Error>
+		where F: FnMut(Self::Item) -> bool,
+	{
+		while let Some(item) = self.next()? {
+			if !f(item) {
+				return Ok(false);
+			}
+		}
+		Ok(true)
+	}
+
+	/// Tests if any element of the iterator matches a predicate, stopping
+	/// at the first error and returning that error.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use try_iterator::prelude::*;
+	///
+	/// let items: Vec<Result<u32, u32>> = vec![Ok(1), Ok(2), Ok(3)];
+	///
+	/// let any_even = convert(items.into_iter()).any(|item| item % 2 == 0);
+	///
+	/// assert_eq!(any_even, Ok(true));
+	/// ```
+	fn any<F>(&mut self, mut f: F) -> Result<bool, Self::Error>
+		where F: FnMut(Self::Item) -> bool,
+	{
+		while let Some(item) = self.next()? {
+			if f(item) {
+				return Ok(true);
+			}
+		}
+		Ok(false)
+	}
+
+	/// Searches for an element in the iterator, returning its index,
+	/// stopping at the first error and returning that error.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use try_iterator::prelude::*;
+	///
+	/// let items: Vec<Result<&str, u32>> = vec![Ok("foo"), Ok("ayy"), Ok("bar")];
+	///
+	/// let pos = convert(items.into_iter()).position(|item| item == "bar");
+	///
+	/// assert_eq!(pos, Ok(Some(2)));
+	/// ```
+	fn position<F>(&mut self, mut f: F) -> Result<Option<usize>, Self::Error>
+		where F: FnMut(Self::Item) -> bool,
+	{
+		let mut idx = 0;
+		while let Some(item) = self.next()? {
+			if f(item) {
+				return Ok(Some(idx));
+			}
+			idx += 1;
+		}
+		Ok(None)
+	}
+
+	/// Turns this fallible iterator into an ordinary [`Iterator`] yielding
+	/// `Result<Self::Item, Self::Error>`, ending for good right after the
+	/// first error.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use try_iterator::prelude::*;
+	///
+	/// let items: Vec<Result<u32, u32>> = vec![Ok(1), Ok(2), Ok(3)];
+	///
+	/// let back: Vec<_> = convert(items.into_iter()).iterator().collect();
+	///
+	/// assert_eq!(back, vec![Ok(1), Ok(2), Ok(3)]);
+	/// ```
+	fn iterator(self) -> Iter<Self>
+		where Self: Sized,
+	{
+		Iter { iter: self, done: false }
+	}
+}
+
+/// Bridges an ordinary [`Iterator`] yielding `Result<T, E>` into a
+/// [`FallibleIterator`], so it can be driven through this crate's fallible
+/// combinators instead.
+///
+/// # Examples
+///
+/// ```
+/// use try_iterator::prelude::*;
+///
+/// let items: Vec<Result<u32, u32>> = vec![Ok(1), Ok(2), Ok(3)];
+///
+/// let sum = convert(items.into_iter()).fold(0, |acc, item| acc + item);
+///
+/// assert_eq!(sum, Ok(6));
+/// ```
+pub fn convert<I, T, E>(iter: I) -> Convert<I>
+	where I: Iterator<Item = Result<T, E>>,
+{
+	Convert { iter }
+}
+
+/// Fallible iterator adapter returned by [`convert()`].
+pub struct Convert<I> {
+	iter: I,
+}
+
+impl<I, T, E> FallibleIterator for Convert<I>
+	where I: Iterator<Item = Result<T, E>>,
+{
+	type Item = T;
+	type Error = E;
+
+	fn next(&mut self) -> Result<Option<T>, E> {
+		self.iter.next().transpose()
+	}
+}
+
+/// Fallible iterator adapter returned by
+/// [`FallibleIterator::map()`].
+pub struct Map<I, F> {
+	iter: I,
+	f: F,
+}
+
+impl<B, I, F> FallibleIterator for Map<I, F>
+	where I: FallibleIterator,
+		F: FnMut(I::Item) -> B,
+{
+	type Item = B;
+	type Error = I::Error;
+
+	fn next(&mut self) -> Result<Option<B>, I::Error> {
+		match self.iter.next()? {
+			Some(item) => Ok(Some((self.f)(item))),
+			None => Ok(None),
+		}
+	}
+}
+
+/// Fallible iterator adapter returned by
+/// [`FallibleIterator::filter()`].
+pub struct Filter<I, P> {
+	iter: I,
+	predicate: P,
+}
+
+impl<I, P> FallibleIterator for Filter<I, P>
+	where I: FallibleIterator,
+		P: FnMut(&I::Item) -> bool,
+{
+	type Item = I::Item;
+	type Error = I::Error;
+
+	fn next(&mut self) -> Result<Option<I::Item>, I::Error> {
+		while let Some(item) = self.iter.next()? {
+			if (self.predicate)(&item) {
+				return Ok(Some(item));
+			}
+		}
+		Ok(None)
+	}
+}
+
+/// Ordinary [`Iterator`] adapter returned by
+/// [`FallibleIterator::iterator()`], yielding
+/// `Result<I::Item, I::Error>` and ending for good right after the first
+/// error.
+pub struct Iter<I> {
+	iter: I,
+	done: bool,
+}
+
+impl<I: FallibleIterator> Iterator for Iter<I> {
+	type Item = Result<I::Item, I::Error>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.done {
+			return None;
+		}
+		match self.iter.next() {
+			Ok(Some(item)) => Some(Ok(item)),
+			Ok(None) => {
+				self.done = true;
+				None
+			},
+			Err(e) => {
+				self.done = true;
+				Some(Err(e))
+			},
+		}
+	}
+}
@@ -0,0 +1,93 @@
+/// Creates a lazy, fallible iterator whose inner iterator is only built on
+/// the first call to `next()`.
+///
+/// This collapses the common but awkward
+/// `Result<impl Iterator<Item = Result<T, E>>, E>` shape — where both
+/// *building* the iterator and *driving* it can fail — into a single
+/// `impl Iterator<Item = Result<T, E>>`, which composes directly with this
+/// crate's `try_*` methods.
+///
+/// `init` is an [`FnOnce`] run exactly once, on the first `next()` call. If
+/// it returns [`Err`], that error is yielded once and the iterator ends for
+/// good. If it returns [`Ok`], the inner value is cached and every
+/// subsequent `next()` call delegates to `next`.
+///
+/// # Examples
+///
+/// Ordinary operation:
+///
+/// ```
+/// use try_iterator::prelude::*;
+/// use std::cell::Cell;
+///
+/// let built = Cell::new(false);
+///
+/// let mut iter = lazy_try(
+///     || -> Result<_, u32> {
+///         built.set(true);
+///         Ok(vec![1, 2, 3].into_iter())
+///     },
+///     |inner| inner.next().map(Ok),
+/// );
+///
+/// assert_eq!(built.get(), false);
+/// assert_eq!(iter.next(), Some(Ok(1)));
+/// assert_eq!(built.get(), true);
+/// assert_eq!(iter.next(), Some(Ok(2)));
+/// assert_eq!(iter.next(), Some(Ok(3)));
+/// assert_eq!(iter.next(), None);
+/// ```
+///
+/// A failing `init` yields a single [`Err`], then ends:
+///
+/// ```
+/// use try_iterator::prelude::*;
+///
+/// let mut iter = lazy_try(
+///     || -> Result<std::vec::IntoIter<u32>, u32> { Err(1234) },
+///     |inner| inner.next().map(Ok),
+/// );
+///
+/// assert_eq!(iter.next(), Some(Err(1234)));
+/// assert_eq!(iter.next(), None);
+/// ```
+pub fn lazy_try<T, E, I, F, G>(init: F, next: G) -> LazyTry<I, F, G>
+	where F: FnOnce() -> Result<I, E>,
+		G: FnMut(&mut I) -> Option<Result<T, E>>,
+{
+	LazyTry { state: LazyState::Uninit(init), next }
+}
+
+/// Iterator adapter returned by [`lazy_try()`].
+pub struct LazyTry<I, F, G> {
+	state: LazyState<I, F>,
+	next: G,
+}
+
+enum LazyState<I, F> {
+	Uninit(F),
+	Ready(I),
+	Done,
+}
+
+impl<T, E, I, F, G> Iterator for LazyTry<I, F, G>
+	where F: FnOnce() -> Result<I, E>,
+		G: FnMut(&mut I) -> Option<Result<T, E>>,
+{
+	type Item = Result<T, E>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let mut inner = match std::mem::replace(&mut self.state, LazyState::Done) {
+			LazyState::Uninit(init) => match init() {
+				Ok(inner) => inner,
+				Err(e) => return Some(Err(e)),
+			},
+			LazyState::Ready(inner) => inner,
+			LazyState::Done => return None,
+		};
+
+		let item = (self.next)(&mut inner);
+		self.state = LazyState::Ready(inner);
+		item
+	}
+}
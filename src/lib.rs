@@ -1,5 +1,18 @@
 #![doc = include_str!("lib.md")]
+#![feature(try_trait_v2)]
+#![feature(try_trait_v2_residual)]
 
+//! # Toolchain
+//!
+//! This crate requires a nightly compiler: `try_all_with`, `try_any_with`,
+//! `try_position_with`, and `try_rposition_with` on
+//! [`TryIterator`](crate::prelude::TryIterator) rely on the unstable
+//! `try_trait_v2` and `try_trait_v2_residual` features. See
+//! `rust-toolchain.toml` in the repo root for a pinned, known-good
+//! nightly.
+
+mod fallible_iterator;
+mod lazy;
 mod try_iterator;
 
 pub mod prelude {
@@ -10,5 +23,7 @@ pub mod prelude {
 	//! use try_iterator::prelude::*;
 	//! ```
 
+    pub use super::fallible_iterator::*;
+    pub use super::lazy::*;
     pub use super::try_iterator::*;
 }
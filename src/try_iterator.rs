@@ -1,9 +1,29 @@
+use std::cmp::Ordering;
+use std::ops::{ControlFlow, FromResidual, Residual, Try};
+
+/// The `Try` type obtained by swapping the `Output` of `R` for `O`, keeping
+/// its `Residual` (e.g. the error type) the same. Used to let
+/// `try_position_with`/`try_rposition_with` return `Option<usize>` or
+/// `Result<Option<usize>, E>` instead of the predicate's own `bool`-shaped
+/// `Try` type.
+type ChangeOutputType<R, O> = <<R as Try>::Residual as Residual<O>>::TryType;
+
 /// Implements the following fallible iterator methods:
 ///
 /// * `try_all`;
 /// * `try_any`;
 /// * `try_position`;
-/// * `try_rposition`.
+/// * `try_rposition`;
+/// * `try_fold_result`;
+/// * `try_for_each_result`;
+/// * `try_find`;
+/// * `try_all_with`;
+/// * `try_any_with`;
+/// * `try_position_with`;
+/// * `try_rposition_with`;
+/// * `try_max_by`;
+/// * `try_min_by`;
+/// * `try_reduce`.
 ///
 /// Prefer importing this trait through the crate prelude:
 ///
@@ -250,6 +270,643 @@ pub trait TryIterator: Iterator {
 		}
 		Ok(None)
 	}
+
+	/// Folds every element into an accumulator by applying an operation,
+	/// stopping at the first error and returning that error.
+	///
+	/// This can also be thought of as the fallible form of
+	/// [`fold()`](Iterator::fold).
+	///
+	/// Named `try_fold_result` rather than `try_fold`: [`Iterator`] already
+	/// has a stable `try_fold` method, so a method of the same name on this
+	/// trait would make calls to it ambiguous.
+	///
+	/// # Examples
+	///
+	/// Ordinary operation:
+	///
+	/// ```
+	/// use try_iterator::prelude::*;
+	///
+	/// let items: &[Result<u32, u32>] = &[
+	///     Ok(1),
+	///     Ok(2),
+	///     Ok(3),
+	/// ];
+	///
+	/// let res = items.iter()
+	///     .try_fold_result(0, |acc, item| -> Result<_, u32> {
+	///         Ok(acc + (*item)?)
+	///     });
+	///
+	/// assert_eq!(res.is_ok(), true);
+	/// assert_eq!(res.unwrap(), 6);
+	/// ```
+	///
+	/// Fails the whole operation when an [`Err`] is present:
+	///
+	/// ```
+	/// use try_iterator::prelude::*;
+	///
+	/// let items: &[Result<u32, u32>] = &[
+	///     Ok(1),
+	///     Err(1111),
+	///     Ok(3),
+	/// ];
+	///
+	/// let res = items.iter()
+	///     .try_fold_result(0, |acc, item| -> Result<_, u32> {
+	///         Ok(acc + (*item)?)
+	///     });
+	///
+	/// assert_eq!(res.is_err(), true);
+	/// ```
+	fn try_fold_result<B, E, F>(&mut self, init: B, mut f: F) -> Result<B, E>
+		where Self: Sized,
+			F: FnMut(B, Self::Item) -> Result<B, E>,
+	{
+		let mut acc = init;
+		for item in self {
+			acc = f(acc, item)?;
+		}
+		Ok(acc)
+	}
+
+	/// Calls a closure on each element, stopping at the first error and
+	/// returning that error.
+	///
+	/// This can also be thought of as the fallible form of
+	/// [`for_each()`](Iterator::for_each).
+	///
+	/// Named `try_for_each_result` rather than `try_for_each`: [`Iterator`]
+	/// already has a stable `try_for_each` method, so a method of the same
+	/// name on this trait would make calls to it ambiguous.
+	///
+	/// # Examples
+	///
+	/// Ordinary operation:
+	///
+	/// ```
+	/// use try_iterator::prelude::*;
+	///
+	/// let items: &[Result<u32, u32>] = &[
+	///     Ok(1),
+	///     Ok(2),
+	///     Ok(3),
+	/// ];
+	///
+	/// let mut sum = 0;
+	/// let res = items.iter()
+	///     .try_for_each_result(|item| -> Result<_, u32> {
+	///         sum += (*item)?;
+	///         Ok(())
+	///     });
+	///
+	/// assert_eq!(res.is_ok(), true);
+	/// assert_eq!(sum, 6);
+	/// ```
+	///
+	/// Fails the whole operation when an [`Err`] is present:
+	///
+	/// ```
+	/// use try_iterator::prelude::*;
+	///
+	/// let items: &[Result<u32, u32>] = &[
+	///     Ok(1),
+	///     Err(2222),
+	///     Ok(3),
+	/// ];
+	///
+	/// let res = items.iter()
+	///     .try_for_each_result(|item| -> Result<_, u32> {
+	///         (*item)?;
+	///         Ok(())
+	///     });
+	///
+	/// assert_eq!(res.is_err(), true);
+	/// ```
+	fn try_for_each_result<E, F>(&mut self, mut f: F) -> Result<(), E>
+		where Self: Sized,
+			F: FnMut(Self::Item) -> Result<(), E>,
+	{
+		self.try_fold_result((), |_, item| f(item))
+	}
+
+	/// Searches for an element in an iterator, returning it, stopping at the
+	/// first error and returning that error.
+	///
+	/// This can also be thought of as the fallible form of
+	/// [`find()`](Iterator::find).
+	///
+	/// # Examples
+	///
+	/// Ordinary operation:
+	///
+	/// ```
+	/// use try_iterator::prelude::*;
+	///
+	/// let items: &[Result<&str, u32>] = &[
+	///     Ok("foo"),
+	///     Ok("ayy"),
+	///     Ok("bar"),
+	/// ];
+	///
+	/// let found = items.iter()
+	///     .try_find(|item| -> Result<_, u32> {
+	///         let equal = (*(*item))? == "bar";
+	///         Ok(equal)
+	///     });
+	///
+	/// assert_eq!(found.is_ok(), true);
+	/// assert_eq!(found.unwrap(), Some(&Ok("bar")));
+	/// ```
+	///
+	/// Fails the whole operation when an [`Err`] is present:
+	///
+	/// ```
+	/// use try_iterator::prelude::*;
+	///
+	/// let items: &[Result<&str, u32>] = &[
+	///     Ok("foo"),
+	///     Err(5555),
+	///     Ok("bar"),
+	/// ];
+	///
+	/// let found = items.iter()
+	///     .try_find(|item| -> Result<_, u32> {
+	///         let equal = (*(*item))? == "bar";
+	///         Ok(equal)
+	///     });
+	///
+	/// assert_eq!(found.is_err(), true);
+	/// ```
+	fn try_find<E, F>(&mut self, mut predicate: F) -> Result<Option<Self::Item>, E>
+		where Self: Sized,
+			F: FnMut(&Self::Item) -> Result<bool, E>,
+	{
+		for item in self {
+			if predicate(&item)? {
+				return Ok(Some(item));
+			}
+		}
+		Ok(None)
+	}
+
+	/// Tests if every element of the iterator matches a predicate, stopping
+	/// at the first short-circuit and returning it.
+	///
+	/// This is the generalized form of [`try_all()`](TryIterator::try_all):
+	/// instead of being hard-wired to `Result<bool, E>`, the predicate may
+	/// return any type implementing [`Try<Output = bool>`](Try), such as
+	/// `Option<bool>` or `Result<bool, E>`, mirroring how core's iterator
+	/// adapters are generalized over the `Try` trait.
+	///
+	/// # Examples
+	///
+	/// Ordinary operation:
+	///
+	/// ```
+	/// use try_iterator::prelude::*;
+	///
+	/// let items = [Some("foo"), Some("foo"), Some("foo")];
+	///
+	/// let res = items.iter()
+	///     .try_all_with(|item| -> Option<bool> {
+	///         Some((*item)? == "foo")
+	///     });
+	///
+	/// assert_eq!(res, Some(true));
+	/// ```
+	///
+	/// Short-circuits on the first `None`:
+	///
+	/// ```
+	/// use try_iterator::prelude::*;
+	///
+	/// let items = [Some("foo"), None, Some("foo")];
+	///
+	/// let res = items.iter()
+	///     .try_all_with(|item| -> Option<bool> {
+	///         Some((*item)? == "foo")
+	///     });
+	///
+	/// assert_eq!(res, None);
+	/// ```
+	///
+	/// With a `Result` predicate:
+	///
+	/// ```
+	/// use try_iterator::prelude::*;
+	///
+	/// let items: &[Result<&str, u32>] = &[
+	///     Ok("foo"),
+	///     Err(4444),
+	///     Ok("foo"),
+	/// ];
+	///
+	/// let res = items.iter()
+	///     .try_all_with(|item| -> Result<bool, u32> {
+	///         Ok((*item)? == "foo")
+	///     });
+	///
+	/// assert_eq!(res.is_err(), true);
+	/// ```
+	fn try_all_with<R, F>(&mut self, mut predicate: F) -> R
+		where Self: Sized,
+			R: Try<Output = bool>,
+			F: FnMut(Self::Item) -> R,
+	{
+		for item in self {
+			match predicate(item).branch() {
+				ControlFlow::Continue(true) => continue,
+				ControlFlow::Continue(false) => return R::from_output(false),
+				ControlFlow::Break(residual) => return R::from_residual(residual),
+			}
+		}
+		R::from_output(true)
+	}
+
+	/// Tests if any element of the iterator matches a predicate, stopping
+	/// at the first short-circuit and returning it.
+	///
+	/// This is the generalized form of [`try_any()`](TryIterator::try_any):
+	/// instead of being hard-wired to `Result<bool, E>`, the predicate may
+	/// return any type implementing [`Try<Output = bool>`](Try), such as
+	/// `Option<bool>` or `Result<bool, E>`.
+	///
+	/// # Examples
+	///
+	/// Ordinary operation:
+	///
+	/// ```
+	/// use try_iterator::prelude::*;
+	///
+	/// let items = [Some("foo"), Some("ayy"), Some("bar")];
+	///
+	/// let res = items.iter()
+	///     .try_any_with(|item| -> Option<bool> {
+	///         Some((*item)? == "bar")
+	///     });
+	///
+	/// assert_eq!(res, Some(true));
+	/// ```
+	///
+	/// Short-circuits on the first `None`:
+	///
+	/// ```
+	/// use try_iterator::prelude::*;
+	///
+	/// let items = [Some("foo"), None, Some("bar")];
+	///
+	/// let res = items.iter()
+	///     .try_any_with(|item| -> Option<bool> {
+	///         Some((*item)? == "bar")
+	///     });
+	///
+	/// assert_eq!(res, None);
+	/// ```
+	///
+	/// With a `Result` predicate:
+	///
+	/// ```
+	/// use try_iterator::prelude::*;
+	///
+	/// let items: &[Result<&str, u32>] = &[
+	///     Ok("foo"),
+	///     Err(5555),
+	///     Ok("bar"),
+	/// ];
+	///
+	/// let res = items.iter()
+	///     .try_any_with(|item| -> Result<bool, u32> {
+	///         Ok((*item)? == "bar")
+	///     });
+	///
+	/// assert_eq!(res.is_err(), true);
+	/// ```
+	fn try_any_with<R, F>(&mut self, mut predicate: F) -> R
+		where Self: Sized,
+			R: Try<Output = bool>,
+			F: FnMut(Self::Item) -> R,
+	{
+		for item in self {
+			match predicate(item).branch() {
+				ControlFlow::Continue(true) => return R::from_output(true),
+				ControlFlow::Continue(false) => continue,
+				ControlFlow::Break(residual) => return R::from_residual(residual),
+			}
+		}
+		R::from_output(false)
+	}
+
+	/// Searches for an element in an iterator, returning its index, stopping
+	/// at the first short-circuit and returning it.
+	///
+	/// This is the generalized form of
+	/// [`try_position()`](TryIterator::try_position): instead of being
+	/// hard-wired to `Result<bool, E>`, the predicate may return any type
+	/// implementing [`Try<Output = bool>`](Try).
+	///
+	/// # Examples
+	///
+	/// Ordinary operation:
+	///
+	/// ```
+	/// use try_iterator::prelude::*;
+	///
+	/// let items = [Some("foo"), Some("ayy"), Some("bar")];
+	///
+	/// let pos = items.iter()
+	///     .try_position_with(|item| -> Option<bool> {
+	///         Some((*item)? == "bar")
+	///     });
+	///
+	/// assert_eq!(pos, Some(Some(2)));
+	/// ```
+	///
+	/// Short-circuits on the first `None`:
+	///
+	/// ```
+	/// use try_iterator::prelude::*;
+	///
+	/// let items = [Some("foo"), None, Some("bar")];
+	///
+	/// let pos = items.iter()
+	///     .try_position_with(|item| -> Option<bool> {
+	///         Some((*item)? == "bar")
+	///     });
+	///
+	/// assert_eq!(pos, None);
+	/// ```
+	///
+	/// With a `Result` predicate:
+	///
+	/// ```
+	/// use try_iterator::prelude::*;
+	///
+	/// let items: &[Result<&str, u32>] = &[
+	///     Ok("foo"),
+	///     Err(6666),
+	///     Ok("bar"),
+	/// ];
+	///
+	/// let pos = items.iter()
+	///     .try_position_with(|item| -> Result<bool, u32> {
+	///         Ok((*item)? == "bar")
+	///     });
+	///
+	/// assert_eq!(pos.is_err(), true);
+	/// ```
+	fn try_position_with<R, F>(&mut self, mut predicate: F) -> ChangeOutputType<R, Option<usize>>
+		where Self: Sized,
+			R: Try<Output = bool>,
+			R::Residual: Residual<Option<usize>>,
+			F: FnMut(Self::Item) -> R,
+	{
+		for (idx, item) in self.enumerate() {
+			match predicate(item).branch() {
+				ControlFlow::Continue(true) => return Try::from_output(Some(idx)),
+				ControlFlow::Continue(false) => continue,
+				ControlFlow::Break(residual) => return FromResidual::from_residual(residual),
+			}
+		}
+		Try::from_output(None)
+	}
+
+	/// Searches for an element in an iterator from the right, returning its
+	/// index, stopping at the first short-circuit and returning it.
+	///
+	/// This is the generalized form of
+	/// [`try_rposition()`](TryIterator::try_rposition): instead of being
+	/// hard-wired to `Result<bool, E>`, the predicate may return any type
+	/// implementing [`Try<Output = bool>`](Try).
+	///
+	/// # Examples
+	///
+	/// Ordinary operation:
+	///
+	/// ```
+	/// use try_iterator::prelude::*;
+	///
+	/// let items = [Some("foo"), Some("ayy"), Some("bar")];
+	///
+	/// let pos = items.iter()
+	///     .try_rposition_with(|item| -> Option<bool> {
+	///         Some((*item)? == "foo")
+	///     });
+	///
+	/// assert_eq!(pos, Some(Some(0)));
+	/// ```
+	///
+	/// Short-circuits on the first `None`:
+	///
+	/// ```
+	/// use try_iterator::prelude::*;
+	///
+	/// let items = [Some("foo"), None, Some("bar")];
+	///
+	/// let pos = items.iter()
+	///     .try_rposition_with(|item| -> Option<bool> {
+	///         Some((*item)? == "foo")
+	///     });
+	///
+	/// assert_eq!(pos, None);
+	/// ```
+	///
+	/// With a `Result` predicate:
+	///
+	/// ```
+	/// use try_iterator::prelude::*;
+	///
+	/// let items: &[Result<&str, u32>] = &[
+	///     Ok("foo"),
+	///     Err(7777),
+	///     Ok("bar"),
+	/// ];
+	///
+	/// let pos = items.iter()
+	///     .try_rposition_with(|item| -> Result<bool, u32> {
+	///         Ok((*item)? == "foo")
+	///     });
+	///
+	/// assert_eq!(pos.is_err(), true);
+	/// ```
+	fn try_rposition_with<R, F>(&mut self, mut predicate: F) -> ChangeOutputType<R, Option<usize>>
+		where Self: Sized + ExactSizeIterator + DoubleEndedIterator,
+			R: Try<Output = bool>,
+			R::Residual: Residual<Option<usize>>,
+			F: FnMut(Self::Item) -> R,
+	{
+		for (idx, item) in self.enumerate().rev() {
+			match predicate(item).branch() {
+				ControlFlow::Continue(true) => return Try::from_output(Some(idx)),
+				ControlFlow::Continue(false) => continue,
+				ControlFlow::Break(residual) => return FromResidual::from_residual(residual),
+			}
+		}
+		Try::from_output(None)
+	}
+
+	/// Returns the element that gives the maximum value with respect to a
+	/// fallible comparator, stopping at the first error and returning that
+	/// error.
+	///
+	/// If several elements are equally maximum, the last one is returned.
+	///
+	/// This can also be thought of as the fallible form of
+	/// [`max_by()`](Iterator::max_by).
+	///
+	/// # Examples
+	///
+	/// Ordinary operation:
+	///
+	/// ```
+	/// use try_iterator::prelude::*;
+	///
+	/// let items: &[Result<u32, u32>] = &[Ok(1), Ok(3), Ok(2)];
+	///
+	/// let max = items.iter()
+	///     .try_max_by(|best, item| -> Result<_, u32> {
+	///         Ok((**best)?.cmp(&(**item)?))
+	///     });
+	///
+	/// assert_eq!(max, Ok(Some(&Ok(3))));
+	/// ```
+	///
+	/// Fails the whole operation when an [`Err`] is present:
+	///
+	/// ```
+	/// use try_iterator::prelude::*;
+	///
+	/// let items: &[Result<u32, u32>] = &[Ok(1), Err(6666), Ok(2)];
+	///
+	/// let max = items.iter()
+	///     .try_max_by(|best, item| -> Result<_, u32> {
+	///         Ok((**best)?.cmp(&(**item)?))
+	///     });
+	///
+	/// assert_eq!(max.is_err(), true);
+	/// ```
+	fn try_max_by<E, F>(mut self, mut compare: F) -> Result<Option<Self::Item>, E>
+		where Self: Sized,
+			F: FnMut(&Self::Item, &Self::Item) -> Result<Ordering, E>,
+	{
+		let mut best = match self.next() {
+			Some(item) => item,
+			None => return Ok(None),
+		};
+		for item in self {
+			if compare(&best, &item)? != Ordering::Greater {
+				best = item;
+			}
+		}
+		Ok(Some(best))
+	}
+
+	/// Returns the element that gives the minimum value with respect to a
+	/// fallible comparator, stopping at the first error and returning that
+	/// error.
+	///
+	/// If several elements are equally minimum, the first one is returned.
+	///
+	/// This can also be thought of as the fallible form of
+	/// [`min_by()`](Iterator::min_by).
+	///
+	/// # Examples
+	///
+	/// Ordinary operation:
+	///
+	/// ```
+	/// use try_iterator::prelude::*;
+	///
+	/// let items: &[Result<u32, u32>] = &[Ok(3), Ok(1), Ok(2)];
+	///
+	/// let min = items.iter()
+	///     .try_min_by(|best, item| -> Result<_, u32> {
+	///         Ok((**best)?.cmp(&(**item)?))
+	///     });
+	///
+	/// assert_eq!(min, Ok(Some(&Ok(1))));
+	/// ```
+	///
+	/// Fails the whole operation when an [`Err`] is present:
+	///
+	/// ```
+	/// use try_iterator::prelude::*;
+	///
+	/// let items: &[Result<u32, u32>] = &[Ok(3), Err(7777), Ok(2)];
+	///
+	/// let min = items.iter()
+	///     .try_min_by(|best, item| -> Result<_, u32> {
+	///         Ok((**best)?.cmp(&(**item)?))
+	///     });
+	///
+	/// assert_eq!(min.is_err(), true);
+	/// ```
+	fn try_min_by<E, F>(mut self, mut compare: F) -> Result<Option<Self::Item>, E>
+		where Self: Sized,
+			F: FnMut(&Self::Item, &Self::Item) -> Result<Ordering, E>,
+	{
+		let mut best = match self.next() {
+			Some(item) => item,
+			None => return Ok(None),
+		};
+		for item in self {
+			if compare(&best, &item)? == Ordering::Greater {
+				best = item;
+			}
+		}
+		Ok(Some(best))
+	}
+
+	/// Reduces the elements to a single one, by repeatedly applying a
+	/// fallible reducing operation, stopping at the first error and
+	/// returning that error.
+	///
+	/// This can also be thought of as the fallible form of
+	/// [`reduce()`](Iterator::reduce).
+	///
+	/// # Examples
+	///
+	/// Ordinary operation:
+	///
+	/// ```
+	/// use try_iterator::prelude::*;
+	///
+	/// let items: &[Result<u32, u32>] = &[Ok(1), Ok(2), Ok(3)];
+	///
+	/// let res = items.iter()
+	///     .try_reduce(|acc, item| -> Result<_, u32> {
+	///         Ok(if (*acc)? > (*item)? { acc } else { item })
+	///     });
+	///
+	/// assert_eq!(res, Ok(Some(&Ok(3))));
+	/// ```
+	///
+	/// Fails the whole operation when an [`Err`] is present:
+	///
+	/// ```
+	/// use try_iterator::prelude::*;
+	///
+	/// let items: &[Result<u32, u32>] = &[Ok(1), Err(8888), Ok(3)];
+	///
+	/// let res = items.iter()
+	///     .try_reduce(|acc, item| -> Result<_, u32> {
+	///         Ok(if (*acc)? > (*item)? { acc } else { item })
+	///     });
+	///
+	/// assert_eq!(res.is_err(), true);
+	/// ```
+	fn try_reduce<E, F>(mut self, f: F) -> Result<Option<Self::Item>, E>
+		where Self: Sized,
+			F: FnMut(Self::Item, Self::Item) -> Result<Self::Item, E>,
+	{
+		let first = match self.next() {
+			Some(item) => item,
+			None => return Ok(None),
+		};
+		self.try_fold_result(first, f).map(Some)
+	}
 }
 
 impl<'a, T> TryIterator for core::slice::Iter<'a, T> {}